@@ -6,7 +6,7 @@ use ratatui::widgets::block::Position;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     text::{Line, Text},
-    widgets::{block::Title, Block, Borders, Table, Clear},
+    widgets::{block::Title, Block, Borders, Gauge, Table, Clear},
     DefaultTerminal, Frame,
 };
 use std::{fmt::Display, io};
@@ -27,6 +27,7 @@ enum Status {
 enum State {
     Home,
     New,
+    Edit(usize),
 }
 
 impl Display for Status {
@@ -47,10 +48,37 @@ struct Task {
     title: String,
     description: String,
     status: Status,
+    // Sticky once the user manually cycles a task off of `Overdue`, so
+    // `refresh_overdue` doesn't immediately flip it right back before the
+    // next draw — otherwise a task whose deadline has passed could never
+    // be marked `InProgress`/`Complete` again.
+    #[serde(default = "default_auto_overdue")]
+    auto_overdue: bool,
+}
+
+fn default_auto_overdue() -> bool {
+    true
+}
+
+impl Task {
+    /// Returns `true` if this flipped the task to `Overdue`.
+    fn refresh_overdue(&mut self) -> bool {
+        if self.auto_overdue
+            && matches!(self.status, Status::NotStarted | Status::InProgress)
+            && self.created + self.duration < chrono::Local::now()
+        {
+            self.status = Status::Overdue;
+            true
+        } else {
+            false
+        }
+    }
 }
 
 fn is_number(textarea: &mut TextArea) -> bool {
-    match textarea.lines()[0].parse::<i32>() {
+    // Parse as `u64` (not `i32`) so this rejects the same negative spans
+    // that would otherwise panic when the span is re-parsed into a `Duration`.
+    match textarea.lines()[0].parse::<u64>() {
         Ok(_) => {
             textarea.set_style(Style::default().fg(Color::LightGreen));
             textarea.set_block(
@@ -74,6 +102,45 @@ fn is_number(textarea: &mut TextArea) -> bool {
     }
 }
 
+fn elapsed_fraction(task: &Task) -> f64 {
+    let elapsed = chrono::Local::now() - task.created;
+    let elapsed = elapsed.to_std().unwrap_or(std::time::Duration::ZERO);
+    if task.duration.is_zero() {
+        return 1.0;
+    }
+    (elapsed.as_secs_f64() / task.duration.as_secs_f64()).clamp(0.0, 1.0)
+}
+
+fn gauge_color(fraction: f64) -> Color {
+    if fraction < 0.5 {
+        Color::Green
+    } else if fraction < 0.8 {
+        Color::Yellow
+    } else {
+        Color::Red
+    }
+}
+
+fn description_block() -> Block<'static> {
+    let instruction = Title::from(Line::from(vec![
+        " Exit ".into(),
+        "<Esc>".blue().bold(),
+        " Next ".into(),
+        "<Tab>".blue().bold(),
+        " Save ".into(),
+        "<Enter>".blue().bold(),
+    ]));
+
+    Block::default()
+        .borders(Borders::ALL)
+        .title(Title::from("Description"))
+        .title(
+            instruction
+                .alignment(Alignment::Center)
+                .position(Position::Bottom),
+        )
+}
+
 fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
     let vertical = Layout::vertical([Constraint::Percentage(percent_y)]).flex(Flex::Center);
     let horizontal = Layout::horizontal([Constraint::Percentage(percent_x)]).flex(Flex::Center);
@@ -82,12 +149,37 @@ fn popup_area(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
     area
 }
 
+fn relative_due_date(due: chrono::DateTime<chrono::Local>) -> String {
+    let delta = due - chrono::Local::now();
+    let seconds = delta.num_seconds();
+    let (phrase, past) = if seconds.abs() < 60 {
+        ("now".to_string(), false)
+    } else if seconds.abs() < 3600 {
+        let minutes = seconds.abs() / 60;
+        (format!("{} minute{}", minutes, if minutes == 1 { "" } else { "s" }), seconds < 0)
+    } else if seconds.abs() < 86400 {
+        let hours = seconds.abs() / 3600;
+        (format!("{} hour{}", hours, if hours == 1 { "" } else { "s" }), seconds < 0)
+    } else {
+        let days = seconds.abs() / 86400;
+        (format!("{} day{}", days, if days == 1 { "" } else { "s" }), seconds < 0)
+    };
+
+    if phrase == "now" {
+        "due now".to_string()
+    } else if past {
+        format!("{} ago", phrase)
+    } else {
+        format!("in {}", phrase)
+    }
+}
+
 impl<'a> From<&Task> for Row<'a> {
     fn from(val: &Task) -> Self {
         Row::new(vec![
             Cell::new(Text::from(format!("{}", val.status))),
             Cell::new(val.title.clone()),
-            Cell::new(Text::from(format!("{}", val.created + val.duration))),
+            Cell::new(Text::from(relative_due_date(val.created + val.duration))),
         ])
     }
 }
@@ -103,12 +195,14 @@ struct App<'a> {
     show_description: bool,
     focus: usize,
     table_state: TableState,
+    dirty: bool,
 }
 
 impl Drop for App<'_> {
     fn drop(&mut self) {
-        let serialized = serde_json::to_string(&self.tasks).unwrap();
-        std::fs::write(&self.file, serialized).unwrap();
+        // Best-effort fallback: `save()` is the explicit path called on quit,
+        // this just catches exits we didn't get a chance to handle cleanly.
+        self.save();
     }
 }
 
@@ -154,14 +248,34 @@ impl App<'_> {
                         None => return,
                     };
 
-                    let paragraph = Paragraph::new(task.description.clone())
-                        .block(block);
+                    let inner = block.inner(popup);
+                    let [due_area, description_area, gauge_area] = Layout::vertical([
+                        Constraint::Length(1),
+                        Constraint::Min(0),
+                        Constraint::Length(1),
+                    ])
+                    .areas(inner);
+
+                    let due_date = Paragraph::new(format!(
+                        "Due: {}",
+                        task.created + task.duration
+                    ));
+                    let paragraph = Paragraph::new(task.description.clone());
+
+                    let fraction = elapsed_fraction(task);
+                    let gauge = Gauge::default()
+                        .gauge_style(Style::default().fg(gauge_color(fraction)))
+                        .label(format!("{:.0}% elapsed", fraction * 100.0))
+                        .ratio(fraction);
 
                     frame.render_widget(Clear, popup);
-                    frame.render_widget(paragraph, popup);
+                    frame.render_widget(block, popup);
+                    frame.render_widget(due_date, due_area);
+                    frame.render_widget(paragraph, description_area);
+                    frame.render_widget(gauge, gauge_area);
                 }
             }
-            State::New => {
+            State::New | State::Edit(_) => {
                 let main_layout = Layout::default()
                     .direction(Direction::Vertical)
                     .constraints([Constraint::Length(3), Constraint::Min(0)])
@@ -180,10 +294,14 @@ impl App<'_> {
     }
 
     fn new(file: &str) -> Self {
-        let tasks = match std::fs::read_to_string(file) {
+        let mut tasks: Vec<Task> = match std::fs::read_to_string(file) {
             Ok(content) => serde_json::from_str(&content).unwrap(),
             Err(_) => Vec::new(),
         };
+        let mut dirty = false;
+        for task in &mut tasks {
+            dirty |= task.refresh_overdue();
+        }
 
         let bordered = Block::default().borders(Borders::ALL);
 
@@ -191,24 +309,9 @@ impl App<'_> {
         let mut title_input = TextArea::default();
         let mut description_input = TextArea::default();
 
-        let instruction = Title::from(Line::from(vec![
-            " Exit ".into(),
-            "<Esc>".blue().bold(),
-            " Next ".into(),
-            "<Tab>".blue().bold(),
-            " Save ".into(),
-            "<Enter>".blue().bold(),
-        ]));
-
         date_input.set_block(bordered.clone().title(Title::from("Span (hrs)")));
         title_input.set_block(bordered.clone().title(Title::from("Title")));
-        description_input.set_block(
-            bordered.clone().title(Title::from("Description")).title(
-                instruction
-                    .alignment(Alignment::Center)
-                    .position(Position::Bottom),
-            ),
-        );
+        description_input.set_block(description_block());
 
         App {
             file: file.to_owned(),
@@ -221,9 +324,50 @@ impl App<'_> {
             show_description: false,
             focus: 0,
             table_state: TableState::default(),
+            dirty,
         }
     }
 
+    fn load_task_form(&mut self, i: usize) {
+        let task = &self.tasks[i];
+        let bordered = Block::default().borders(Borders::ALL);
+
+        self.title_input = TextArea::from(vec![task.title.clone()]);
+        self.title_input
+            .set_block(bordered.clone().title(Title::from("Title")));
+
+        self.date_input = TextArea::from(vec![(task.duration.as_secs() / 3600).to_string()]);
+        self.date_input
+            .set_block(bordered.title(Title::from("Span (hrs)")));
+
+        self.description_input = TextArea::from(task.description.lines().map(String::from));
+        self.description_input.set_block(description_block());
+
+        self.focus = 0;
+    }
+
+    fn save(&mut self) {
+        if !self.dirty {
+            return;
+        }
+
+        if let Err(err) = self.save_to_disk() {
+            eprintln!("failed to save {}: {err}", self.file);
+            return;
+        }
+
+        self.dirty = false;
+    }
+
+    fn save_to_disk(&self) -> io::Result<()> {
+        let serialized = serde_json::to_string(&self.tasks)
+            .map_err(io::Error::other)?;
+        let tmp_path = format!("{}.tmp", self.file);
+        std::fs::write(&tmp_path, serialized)?;
+        std::fs::rename(&tmp_path, &self.file)?;
+        Ok(())
+    }
+
     pub fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
         while !self.exit {
             terminal.draw(|frame| self.draw(frame))?;
@@ -233,6 +377,10 @@ impl App<'_> {
     }
 
     fn next(&mut self) {
+        if self.tasks.is_empty() {
+            self.table_state.select(None);
+            return;
+        }
         let i = match self.table_state.selected() {
             Some(i) => {
                 if i >= self.tasks.len() - 1 {
@@ -247,6 +395,10 @@ impl App<'_> {
     }
 
     fn previous(&mut self) {
+        if self.tasks.is_empty() {
+            self.table_state.select(None);
+            return;
+        }
         let i = match self.table_state.selected() {
             Some(i) => {
                 if i == 0 {
@@ -261,6 +413,9 @@ impl App<'_> {
     }
 
     fn draw(&mut self, frame: &mut Frame) {
+        for task in &mut self.tasks {
+            self.dirty |= task.refresh_overdue();
+        }
         self.render(frame.area(), frame);
     }
 
@@ -274,10 +429,32 @@ impl App<'_> {
 
     fn handle_key_event(&mut self, key_event: KeyEvent) {
         match key_event.code {
-            KeyCode::Char('q') if self.state == State::Home => self.exit = true,
+            KeyCode::Char('q') if self.state == State::Home => {
+                self.save();
+                self.exit = true;
+            }
             KeyCode::Char('n') if self.state == State::Home => self.state = State::New,
-            KeyCode::Esc if self.state == State::New => self.state = State::Home,
-            KeyCode::Char(c) if self.state == State::New => {
+            KeyCode::Char('e') if self.state == State::Home => {
+                if let Some(i) = self.table_state.selected().filter(|&i| i < self.tasks.len()) {
+                    self.load_task_form(i);
+                    self.state = State::Edit(i);
+                }
+            }
+            KeyCode::Char('d') if self.state == State::Home => {
+                if let Some(i) = self.table_state.selected().filter(|&i| i < self.tasks.len()) {
+                    self.tasks.remove(i);
+                    self.dirty = true;
+                    if self.tasks.is_empty() {
+                        self.table_state.select(None);
+                    } else if i >= self.tasks.len() {
+                        self.table_state.select(Some(self.tasks.len() - 1));
+                    }
+                }
+            }
+            KeyCode::Esc if matches!(self.state, State::New | State::Edit(_)) => {
+                self.state = State::Home
+            }
+            KeyCode::Char(c) if matches!(self.state, State::New | State::Edit(_)) => {
                 match self.focus {
                     0 => self.title_input.input(Input {
                         key: Key::Char(c),
@@ -294,7 +471,7 @@ impl App<'_> {
                     _ => unreachable!(),
                 };
             }
-            KeyCode::Backspace if self.state == State::New => {
+            KeyCode::Backspace if matches!(self.state, State::New | State::Edit(_)) => {
                 match self.focus {
                     0 => self.title_input.input(Input {
                         key: Key::Backspace,
@@ -311,7 +488,9 @@ impl App<'_> {
                     _ => unreachable!(),
                 };
             }
-            KeyCode::Tab if self.state == State::New => self.focus = (self.focus + 1) % 3,
+            KeyCode::Tab if matches!(self.state, State::New | State::Edit(_)) => {
+                self.focus = (self.focus + 1) % 3
+            }
             KeyCode::Enter if self.state == State::New => {
                 if is_number(&mut self.date_input) {
                     self.tasks.push(Task {
@@ -322,20 +501,45 @@ impl App<'_> {
                         title: self.title_input.lines()[0].clone(),
                         description: self.description_input.lines().join("\n"),
                         status: Status::NotStarted,
+                        auto_overdue: true,
                     });
+                    self.dirty = true;
+                    self.state = State::Home;
+                }
+            }
+            KeyCode::Enter if matches!(self.state, State::Edit(_)) => {
+                let State::Edit(i) = self.state else {
+                    unreachable!()
+                };
+                if is_number(&mut self.date_input) {
+                    self.tasks[i].title = self.title_input.lines()[0].clone();
+                    self.tasks[i].duration = std::time::Duration::from_secs(
+                        self.date_input.lines()[0].parse::<u64>().unwrap() * 3600,
+                    );
+                    self.tasks[i].description = self.description_input.lines().join("\n");
+                    // A re-dated task re-enters the auto-overdue pool even if it
+                    // was previously latched off by a manual status change.
+                    self.tasks[i].auto_overdue = true;
+                    self.dirty = true;
                     self.state = State::Home;
                 }
             }
             KeyCode::Down if self.state == State::Home => self.next(),
             KeyCode::Up if self.state == State::Home => self.previous(),
             KeyCode::Enter if self.state == State::Home => {
-                let i = self.table_state.selected().unwrap();
-                self.tasks[i].status = match self.tasks[i].status {
-                    Status::NotStarted => Status::InProgress,
-                    Status::InProgress => Status::Complete,
-                    Status::Complete => Status::Overdue,
-                    Status::Overdue => Status::NotStarted,
-                };
+                if let Some(i) = self.table_state.selected().filter(|&i| i < self.tasks.len()) {
+                    let was_overdue = matches!(self.tasks[i].status, Status::Overdue);
+                    self.tasks[i].status = match self.tasks[i].status {
+                        Status::NotStarted => Status::InProgress,
+                        Status::InProgress => Status::Complete,
+                        Status::Complete => Status::Overdue,
+                        Status::Overdue => Status::NotStarted,
+                    };
+                    if was_overdue {
+                        self.tasks[i].auto_overdue = false;
+                    }
+                    self.dirty = true;
+                }
             }
             KeyCode::Char(' ') if self.state == State::Home => {
                 self.show_description = !self.show_description
@@ -346,7 +550,16 @@ impl App<'_> {
     }
 }
 
+fn install_panic_hook() {
+    let original_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        ratatui::restore();
+        original_hook(panic_info);
+    }));
+}
+
 fn main() -> io::Result<()> {
+    install_panic_hook();
     let mut terminal = ratatui::init();
     let mut app = App::new("tasks.json");
     let result = app.run(&mut terminal);